@@ -25,6 +25,27 @@
 //! - [`DefaultEngine`]
 //!     - Default engine which is used when no specific engine is given.
 //!     - Automatically selects best engine at runtime.
+//! - [`NoTable`]
+//!     - Table-free engine for memory-constrained targets (`wasm32`, embedded).
+//!     - Computes products directly instead of consulting precomputed field tables.
+//! - [`Portable`]
+//!     - Optimized engine built on `core::simd` (requires the `portable-simd`
+//!       feature and nightly Rust).
+//!     - Vectorizes on every target with a SIMD backend, including
+//!       `wasm32`, RISC-V and PowerPC, not just x86(-64)/AArch64.
+//!
+//! # GF(2^8) engines
+//!
+//! Whenever `original + recovery <= 256`, the narrower GF(2^8) field in
+//! [`gf8`] is sufficient and roughly doubles throughput while halving
+//! table and shard-element memory compared to the GF(2^16) engines above.
+//! [`gf8::is_applicable`] is the criterion [`rate`] would use to pick a
+//! GF(2^8) engine automatically in that case. Wiring that selection in is
+//! out of this module's reach, though: [`rate`] (and the
+//! [`ReedSolomonEncoder`]/[`ReedSolomonDecoder`] layer above it) isn't part
+//! of this checkout, so there's no call site to change - [`gf8`]'s engines
+//! are only reachable by constructing one directly; see [`gf8::Engine8`]
+//! for the low-level entry points.
 //!
 //! [simple usage]: crate#simple-usage
 //! [basic usage]: crate#basic-usage
@@ -35,7 +56,8 @@
 pub(crate) use self::shards::Shards;
 
 pub use self::{
-    engine_default::DefaultEngine, engine_naive::Naive, engine_nosimd::NoSimd, shards::ShardsRefMut,
+    engine_default::DefaultEngine, engine_naive::Naive, engine_no_table::NoTable,
+    engine_nosimd::NoSimd, shards::ShardsRefMut,
 };
 
 #[cfg(feature = "simd")]
@@ -46,8 +68,12 @@ pub use self::{engine_avx2::Avx2, engine_ssse3::Ssse3};
 #[cfg(target_arch = "aarch64")]
 pub use self::engine_neon::Neon;
 
+#[cfg(feature = "portable-simd")]
+pub use self::engine_portable::Portable;
+
 mod engine_default;
 mod engine_naive;
+mod engine_no_table;
 mod engine_nosimd;
 
 #[cfg(feature = "simd")]
@@ -61,11 +87,16 @@ mod engine_ssse3;
 #[cfg(target_arch = "aarch64")]
 mod engine_neon;
 
+#[cfg(feature = "portable-simd")]
+mod engine_portable;
+
 mod fwht;
 mod shards;
 
 pub mod tables;
 
+pub mod gf8;
+
 // ======================================================================
 // CONST - PUBLIC
 
@@ -81,12 +112,6 @@ pub const GF_MODULUS: GfElement = 65535;
 /// Galois field polynomial.
 pub const GF_POLYNOMIAL: usize = 0x1002D;
 
-/// TODO
-pub const CANTOR_BASIS: [GfElement; GF_BITS] = [
-    0x0001, 0xACCA, 0x3C0E, 0x163E, 0xC582, 0xED2E, 0x914C, 0x4012, 0x6C98, 0x10D8, 0x6A72, 0xB900,
-    0xFDB8, 0xFB34, 0xFF38, 0x991E,
-];
-
 // ======================================================================
 // TYPE ALIASES - PUBLIC
 
@@ -130,6 +155,98 @@ pub(crate) fn eval_poly(erasures: &mut [GfElement; GF_ORDER], truncated_size: us
     fwht::fwht(erasures, GF_ORDER);
 }
 
+// ======================================================================
+// FUNCTIONS - CRATE - FFT/IFFT skew factors
+
+// Builds the log/exp tables `initialize_skew` needs. Same construction
+// every table-based engine uses (generator `2`, reduce by `GF_POLYNOMIAL`),
+// duplicated here rather than shared so this module doesn't have to depend
+// on any one engine's private table layout.
+fn exp_log_tables() -> ([GfElement; GF_ORDER], [GfElement; GF_ORDER]) {
+    let mut exp = [0; GF_ORDER];
+    let mut log = [0; GF_ORDER];
+    let mut x: u32 = 1;
+    for i in 0..usize::from(GF_MODULUS) {
+        exp[i] = x as GfElement;
+        log[x as usize] = i as GfElement;
+        x <<= 1;
+        if x & (1 << GF_BITS) != 0 {
+            x ^= GF_POLYNOMIAL as u32;
+        }
+    }
+    (exp, log)
+}
+
+// `a * exp[log_b]`, `a` being a field element and `log_b` a discrete log -
+// same `mul` contract every engine's `Engine::mul` exposes, used here to
+// fold one normalized basis vector's log into another during
+// `initialize_skew`.
+#[inline(always)]
+fn mul_log(
+    exp: &[GfElement; GF_ORDER],
+    log: &[GfElement; GF_ORDER],
+    a: GfElement,
+    log_b: GfElement,
+) -> GfElement {
+    if a == 0 {
+        0
+    } else {
+        exp[usize::from(add_mod(log[usize::from(a)], log_b))]
+    }
+}
+
+/// Builds the skew-factor table the FFT/IFFT butterfly recursion reads one
+/// entry from per block: entry `r + dist + skew_delta - 1` is the field
+/// element every butterfly in that block multiplies by (`0` means "no
+/// twiddle", see [`NoTable`]/[`Portable`]'s `fft`/`ifft`). [`gf8`] has its
+/// own, GF(2^8)-sized analogue.
+///
+/// This mirrors the reference implementation's recursive construction:
+/// seed `temp` with the naive power-of-two basis, then for each level `m`,
+/// XOR `temp[i]` into every table slot it covers and fold a log-domain
+/// correction derived from `temp[m]` into every later `temp[i]`, so the
+/// next level's slots see a normalized basis rather than the raw one. A
+/// flat XOR-combination of a fixed basis by the bits of the index does
+/// *not* produce a valid skew table this way - it fails even the basic
+/// invariant every block boundary must satisfy, `table[2^m - 1] == 0` (see
+/// `test_initialize_skew_zero_at_block_starts` below).
+pub(crate) fn initialize_skew() -> [GfElement; GF_MODULUS as usize] {
+    let (exp, log) = exp_log_tables();
+
+    let mut temp: [GfElement; GF_BITS - 1] = [0; GF_BITS - 1];
+    for (i, t) in temp.iter_mut().enumerate() {
+        *t = (1u32 << (i + 1)) as GfElement;
+    }
+
+    let mut skew = [0 as GfElement; GF_MODULUS as usize];
+
+    for m in 0..GF_BITS - 1 {
+        let step = 1usize << (m + 1);
+        skew[(1 << m) - 1] = 0;
+
+        for i in m..GF_BITS - 1 {
+            let s = 1usize << (i + 1);
+            let mut j = (1usize << m) - 1;
+            while j < s {
+                skew[j + s] = skew[j] ^ temp[i];
+                j += step;
+            }
+        }
+
+        // `temp[m]` becomes `log(1 / (temp[m] * (temp[m] ^ 1)))`, the factor
+        // that normalizes the remaining basis vectors below.
+        let product = mul_log(&exp, &log, temp[m], log[usize::from(temp[m] ^ 1)]);
+        temp[m] = sub_mod(0, log[usize::from(product)]);
+
+        for i in (m + 1)..GF_BITS - 1 {
+            let sum = add_mod(log[usize::from(temp[i] ^ 1)], temp[m]);
+            temp[i] = mul_log(&exp, &log, temp[i], sum);
+        }
+    }
+
+    skew
+}
+
 // ======================================================================
 // FUNCTIONS - PUBLIC - misc
 
@@ -283,6 +400,10 @@ pub trait Engine {
 // TESTS
 
 // Engines are tested indirectly via roundtrip tests of HighRate and LowRate.
+// `NoTable`/`Portable` predate that wiring in this checkout (`rate.rs` isn't
+// part of it), so `initialize_skew` gets direct invariant tests below
+// instead - a real `fft`/`ifft` roundtrip needs a `ShardsRefMut`, which
+// needs `shards.rs`, which doesn't exist here either.
 
 #[cfg(test)]
 mod tests {
@@ -300,4 +421,107 @@ mod tests {
         assert_eq!(checked_next_multiple_of(100, 20), Some(100));
         assert_eq!(checked_next_multiple_of(101, 20), Some(120));
     }
+
+    // ============================================================
+    // initialize_skew
+
+    #[test]
+    fn test_initialize_skew_zero_at_block_starts() {
+        // Every block the FFT/IFFT recursion visits starts at `dist - 1`
+        // for some power-of-two `dist` (with `skew_delta == 0`, i.e. `r ==
+        // 0`), and that first entry must carry no twiddle at all. A plain
+        // XOR-combination of a fixed basis by the bits of the index fails
+        // this for every `m >= 1` (e.g. index 1 doesn't XOR to 0), which is
+        // exactly the defect that slipped through last round.
+        let skew = initialize_skew();
+        for m in 0..GF_BITS - 1 {
+            assert_eq!(skew[(1 << m) - 1], 0, "skew[2^{m} - 1] should be 0");
+        }
+    }
+
+    #[test]
+    fn test_initialize_skew_nonzero_entries_distinct_per_level() {
+        // Entry `i` belongs to recursion level `i.trailing_ones()`. Within a
+        // level every entry other than the block start (`2^m - 1`, which is
+        // `0`) must be a distinct nonzero field element; a construction that
+        // collapses distinct evaluation points of one level to the same skew
+        // value would silently corrupt unrelated butterflies. Values may
+        // repeat across levels. The only zeros are the `GF_BITS` entries
+        // `2^m - 1` for `m` in `0..GF_BITS` (index `2^15 - 1` is never
+        // written by any level and keeps its initial `0`).
+        let skew = initialize_skew();
+        let mut seen = vec![[false; GF_ORDER]; GF_BITS];
+        let mut nonzero_count = 0;
+        for (index, &value) in skew.iter().enumerate() {
+            if value != 0 {
+                let level = index.trailing_ones() as usize;
+                assert!(
+                    !seen[level][usize::from(value)],
+                    "duplicate skew value {value} at level {level}"
+                );
+                seen[level][usize::from(value)] = true;
+                nonzero_count += 1;
+            }
+        }
+        assert_eq!(nonzero_count, usize::from(GF_MODULUS) - GF_BITS);
+    }
+
+    // ============================================================
+    // NoTable
+
+    // Reference exp table built the same way every table-based engine
+    // builds its own, used to check `NoTable::mul`'s log_m -> value
+    // conversion without depending on `NoTable`'s private internals.
+    fn reference_exp_table() -> [GfElement; GF_ORDER - 1] {
+        let mut exp = [0; GF_ORDER - 1];
+        let mut x: u32 = 1;
+        for e in exp.iter_mut() {
+            *e = x as GfElement;
+            x <<= 1;
+            if x & (1 << GF_BITS) != 0 {
+                x ^= GF_POLYNOMIAL as u32;
+            }
+        }
+        exp
+    }
+
+    #[test]
+    fn test_no_table_mul_matches_reference_exp_table() {
+        let exp = reference_exp_table();
+        let engine = NoTable::new();
+
+        for &log_m in &[0, 1, 2, 12345, usize::from(GF_MODULUS) - 1] {
+            let mut one = 1u16.to_le_bytes();
+            engine.mul(&mut one, log_m as GfElement);
+            assert_eq!(
+                u16::from_le_bytes(one),
+                exp[log_m],
+                "mismatch at log_m={log_m}"
+            );
+        }
+    }
+
+    // ============================================================
+    // Portable
+
+    #[cfg(feature = "portable-simd")]
+    #[test]
+    fn test_portable_mul_matches_no_table() {
+        // Both engines implement the same `Engine::mul(x, log_m)` contract;
+        // `Portable` goes through its own log/exp tables while `NoTable` is
+        // table-free, so agreement here pins down that `Portable`'s switch
+        // from "takes a value" back to "takes a log_m" was done correctly.
+        let portable = Portable::new();
+        let no_table = NoTable::new();
+
+        for &log_m in &[0, 1, 2, 12345, usize::from(GF_MODULUS) - 1] {
+            for &value in &[0u16, 1, 2, 40000, u16::from(GF_MODULUS)] {
+                let mut a = value.to_le_bytes();
+                let mut b = value.to_le_bytes();
+                portable.mul(&mut a, log_m as GfElement);
+                no_table.mul(&mut b, log_m as GfElement);
+                assert_eq!(a, b, "mismatch at log_m={log_m}, value={value}");
+            }
+        }
+    }
 }
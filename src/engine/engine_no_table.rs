@@ -0,0 +1,226 @@
+#[cfg(all(target_arch = "x86", target_feature = "sse2"))]
+use std::arch::x86::{__m128i, _mm_clmulepi64_si128, _mm_cvtsi128_si32, _mm_set_epi64x};
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use std::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_cvtsi128_si32, _mm_set_epi64x};
+
+use super::{initialize_skew, Engine, GfElement, ShardsRefMut, GF_BITS, GF_POLYNOMIAL};
+
+/// Table-free engine for memory-constrained targets.
+///
+/// Every other engine in this module keeps a precomputed field-multiplication
+/// table around (the log-Walsh table plus per-multiplier nibble tables for
+/// the SIMD engines), which is painful on `wasm32`/embedded targets where
+/// static footprint and table-init cost matter. [`NoTable`] instead computes
+/// `a * b` directly over GF(2^16): it's a widening carryless multiply of the
+/// two operands followed by a reduction modulo [`GF_POLYNOMIAL`].
+///
+/// On x86(-64) with `pclmulqdq` the hardware carryless-multiply instruction
+/// does the widening multiply; everywhere else this falls back to a
+/// branch-free Russian-peasant multiplication, reducing by
+/// [`GF_POLYNOMIAL`] whenever bit 16 would be set.
+///
+/// [`Engine::mul`] takes a discrete log like every other engine, so callers
+/// can treat [`NoTable`] as a drop-in replacement. Since there's no log
+/// table to turn `log_m` back into a multiplier, [`Self::mul`] computes
+/// `2^log_m` on the fly via square-and-multiply (`2` generates the
+/// multiplicative group for [`GF_POLYNOMIAL`], same as every other engine's
+/// `exp` table); this costs a handful of extra [`gf_mul`] calls per `mul`,
+/// not a table.
+///
+/// `fft`/`ifft` are the one exception: the skew factors they need can't be
+/// derived per-index cheaply (see [`super::initialize_skew`]), so they
+/// build that table once per call instead of keeping one resident - still
+/// no persistent table in [`NoTable`] itself, just a transient full-size
+/// one (`GF_MODULUS` entries, whatever the transform size).
+#[derive(Clone, Default)]
+pub struct NoTable {}
+
+impl NoTable {
+    /// Creates new [`NoTable`].
+    ///
+    /// This is cheap: there are no tables to initialize.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl NoTable {
+    // FFT butterfly on shards `i` and `j`. `skew` is a field element (see
+    // `initialize_skew`), not a log; `skew == 0` means "no twiddle", so the
+    // multiply is skipped rather than zeroing `x`.
+    fn fft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement) {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        if skew != 0 {
+            Self::mul_value(x, skew);
+            Self::xor(y, x);
+        }
+        Self::xor(x, y);
+    }
+
+    // IFFT butterfly on shards `i` and `j`. See `fft_butterfly` for `skew`.
+    fn ifft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement) {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        Self::xor(x, y);
+        if skew != 0 {
+            Self::mul_value(x, skew);
+            Self::xor(y, x);
+        }
+    }
+
+    // `x[] *= value`, `value` being an actual field element rather than a
+    // log. The FFT/IFFT butterflies already have `value` on hand from
+    // `initialize_skew`, so this skips `mul`'s log_m -> value conversion.
+    fn mul_value(x: &mut [u8], value: GfElement) {
+        for pair in x.chunks_exact_mut(2) {
+            let elem = u16::from_le_bytes([pair[0], pair[1]]);
+            let product = gf_mul(elem, value);
+            pair.copy_from_slice(&product.to_le_bytes());
+        }
+    }
+}
+
+impl Engine for NoTable {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        let skew_table = initialize_skew();
+        let mut dist = size / 2;
+        let mut dist4 = size;
+        while dist != 0 {
+            let mut r = 0;
+            while r < truncated_size {
+                let skew = skew_table[skew_delta + r + dist - 1];
+                for i in r..r + dist {
+                    self.fft_butterfly(data, pos + i, pos + i + dist, skew);
+                }
+                r += dist4;
+            }
+            dist4 = dist;
+            dist /= 2;
+        }
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        let skew_table = initialize_skew();
+        let mut dist = 1;
+        let mut dist4 = 4;
+        while dist4 <= size {
+            let mut r = 0;
+            while r < truncated_size {
+                let skew = skew_table[skew_delta + r + dist - 1];
+                for i in r..r + dist {
+                    self.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+                }
+                r += dist4;
+            }
+            dist = dist4;
+            dist4 *= 4;
+        }
+
+        if dist < size {
+            let skew = skew_table[skew_delta + dist - 1];
+            for i in 0..dist {
+                self.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+            }
+        }
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement) {
+        Self::mul_value(x, gf_pow(2, log_m));
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        for (x, y) in std::iter::zip(x.iter_mut(), y.iter()) {
+            *x ^= *y;
+        }
+    }
+}
+
+// Computes `2^exponent` over GF(2^16) via square-and-multiply, using `2`
+// (the element `x`) as generator - same generator every other engine's
+// `exp` table is built from. `GF_BITS` squarings cover the full exponent
+// range, so this stays table-free.
+#[inline(always)]
+fn gf_pow(base: GfElement, exponent: GfElement) -> GfElement {
+    let mut result: GfElement = 1;
+    let mut square = base;
+    let mut exponent = exponent;
+    while exponent != 0 {
+        if exponent & 1 != 0 {
+            result = gf_mul(result, square);
+        }
+        square = gf_mul(square, square);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Computes `a * b` over GF(2^16), preferring the hardware carryless
+// multiply when available.
+#[inline(always)]
+fn gf_mul(a: GfElement, b: GfElement) -> GfElement {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        if is_x86_feature_detected!("pclmulqdq") {
+            // SAFETY: Just checked that `pclmulqdq` is supported.
+            return unsafe { gf_mul_clmul(a, b) };
+        }
+    }
+
+    gf_mul_peasant(a, b)
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn gf_mul_clmul(a: GfElement, b: GfElement) -> GfElement {
+    let va: __m128i = _mm_set_epi64x(0, i64::from(a));
+    let vb: __m128i = _mm_set_epi64x(0, i64::from(b));
+    let product = _mm_clmulepi64_si128(va, vb, 0x00);
+    // The product of two 16-bit operands fits in the low 32 bits.
+    reduce(_mm_cvtsi128_si32(product) as u32)
+}
+
+// Branch-free Russian-peasant multiplication: for each of the 16 bits of
+// `b`, conditionally XOR a shifted copy of `a` into the result, reducing
+// `a` by `GF_POLYNOMIAL` whenever bit 16 becomes set.
+fn gf_mul_peasant(a: GfElement, b: GfElement) -> GfElement {
+    let mut result: u32 = 0;
+    let mut shifted = u32::from(a);
+    for i in 0..GF_BITS {
+        let mask = (u32::from(b) >> i) & 1;
+        result ^= shifted & mask.wrapping_neg();
+        shifted <<= 1;
+        let carry = (shifted >> GF_BITS) & 1;
+        shifted ^= (GF_POLYNOMIAL as u32) & carry.wrapping_neg();
+    }
+    result as GfElement
+}
+
+// Reduces a 31-bit carryless product modulo `GF_POLYNOMIAL`, top bit down.
+#[inline(always)]
+fn reduce(mut product: u32) -> GfElement {
+    for bit in (GF_BITS..=2 * GF_BITS - 2).rev() {
+        if product & (1 << bit) != 0 {
+            product ^= (GF_POLYNOMIAL as u32) << (bit - GF_BITS);
+        }
+    }
+    product as GfElement
+}
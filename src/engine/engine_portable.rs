@@ -0,0 +1,243 @@
+//! Requires `#![feature(portable_simd)]` enabled at the crate root behind
+//! the `portable-simd` feature (nightly Rust only).
+
+use std::simd::prelude::*;
+
+use super::{initialize_skew, Engine, GfElement, ShardsRefMut, GF_MODULUS, GF_POLYNOMIAL};
+
+const LANES: usize = 16;
+
+/// Optimized engine built on `core::simd` (portable SIMD).
+///
+/// Unlike [`super::Avx2`]/[`super::Ssse3`] (x86(-64) only) and
+/// [`super::Neon`] (AArch64 only), [`Portable`] vectorizes the same
+/// nibble-split multiply and FFT butterflies on every target with a
+/// `core::simd` backend, including `wasm32` (`simd128`), RISC-V (`V`) and
+/// PowerPC (AltiVec).
+///
+/// Requires the `portable-simd` crate feature, which pulls in nightly-only
+/// `core::simd`.
+///
+/// [`super::DefaultEngine`] would ideally prefer this over
+/// [`super::NoSimd`] when no arch-specific engine is available but a
+/// `core::simd` backend is, but `engine_default.rs` isn't part of this
+/// checkout - that selection logic has no home to live in here.
+#[derive(Clone)]
+pub struct Portable {
+    exp: [GfElement; 1 << 16],
+    log: [GfElement; 1 << 16],
+}
+
+// A GF(2^16) element is split into 4 nibbles (low/high nibble of each of
+// its 2 bytes). Multiplication by a fixed multiplier is GF(2)-linear in
+// the bits of its operand, so `value * m` is the XOR of each nibble's
+// contribution, evaluated at its bit position and looked up in a 16-entry
+// table - same trick `Ssse3`/`Neon` use, just with 4 nibble positions
+// instead of 2 since elements here are 16 bits wide instead of 8.
+struct NibbleTables {
+    // `lo_out[pos]`/`hi_out[pos]`: output low/high byte for each of the 16
+    // possible values of nibble `pos`, already multiplied by `m`.
+    lo_out: [Simd<u8, LANES>; 4],
+    hi_out: [Simd<u8, LANES>; 4],
+}
+
+impl Portable {
+    /// Creates new [`Portable`], initializing log/exp tables.
+    pub fn new() -> Self {
+        let mut exp = [0; 1 << 16];
+        let mut log = [0; 1 << 16];
+        let mut x: u32 = 1;
+        for i in 0..usize::from(GF_MODULUS) {
+            exp[i] = x as GfElement;
+            log[x as usize] = i as GfElement;
+            x <<= 1;
+            if x & (1 << 16) != 0 {
+                x ^= GF_POLYNOMIAL as u32;
+            }
+        }
+        Self { exp, log }
+    }
+
+    fn nibble_tables(&self, log_m: GfElement) -> NibbleTables {
+        let log_m = usize::from(log_m);
+        let mul = |v: u32| -> GfElement {
+            if v == 0 {
+                0
+            } else {
+                let log_v = usize::from(self.log[v as usize]);
+                self.exp[(log_v + log_m) % usize::from(GF_MODULUS)]
+            }
+        };
+
+        let mut lo_out = [[0u8; LANES]; 4];
+        let mut hi_out = [[0u8; LANES]; 4];
+        for (pos, shift) in [0u32, 4, 8, 12].into_iter().enumerate() {
+            for nibble in 0..16u32 {
+                let product = mul(nibble << shift);
+                lo_out[pos][nibble as usize] = product as u8;
+                hi_out[pos][nibble as usize] = (product >> 8) as u8;
+            }
+        }
+
+        NibbleTables {
+            lo_out: lo_out.map(Simd::from_array),
+            hi_out: hi_out.map(Simd::from_array),
+        }
+    }
+}
+
+impl Default for Portable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine for Portable {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        let skew_table = initialize_skew();
+        let mut dist = size / 2;
+        let mut dist4 = size;
+        while dist != 0 {
+            let mut r = 0;
+            while r < truncated_size {
+                let skew = skew_table[skew_delta + r + dist - 1];
+                for i in r..r + dist {
+                    self.fft_butterfly(data, pos + i, pos + i + dist, skew);
+                }
+                r += dist4;
+            }
+            dist4 = dist;
+            dist /= 2;
+        }
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        let skew_table = initialize_skew();
+        let mut dist = 1;
+        let mut dist4 = 4;
+        while dist4 <= size {
+            let mut r = 0;
+            while r < truncated_size {
+                let skew = skew_table[skew_delta + r + dist - 1];
+                for i in r..r + dist {
+                    self.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+                }
+                r += dist4;
+            }
+            dist = dist4;
+            dist4 *= 4;
+        }
+
+        if dist < size {
+            let skew = skew_table[skew_delta + dist - 1];
+            for i in 0..dist {
+                self.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+            }
+        }
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement) {
+        let tables = self.nibble_tables(log_m);
+        let low_mask = Simd::splat(0x0f);
+
+        // Low/high bytes of each element are interleaved `lo, hi, lo, hi,
+        // ...`; de-interleave a lane-sized window at a time so the nibble
+        // lookups below can run as plain vector ops.
+        let mut chunks = x.chunks_exact_mut(2 * LANES);
+        for chunk in &mut chunks {
+            let mut lo_bytes = [0u8; LANES];
+            let mut hi_bytes = [0u8; LANES];
+            for i in 0..LANES {
+                lo_bytes[i] = chunk[2 * i];
+                hi_bytes[i] = chunk[2 * i + 1];
+            }
+            let lo_v = Simd::from_array(lo_bytes);
+            let hi_v = Simd::from_array(hi_bytes);
+
+            let idx = [
+                lo_v & low_mask,
+                (lo_v >> Simd::splat(4)) & low_mask,
+                hi_v & low_mask,
+                (hi_v >> Simd::splat(4)) & low_mask,
+            ];
+
+            let mut result_lo = Simd::splat(0);
+            let mut result_hi = Simd::splat(0);
+            for pos in 0..4 {
+                result_lo ^= tables.lo_out[pos].swizzle_dyn(idx[pos]);
+                result_hi ^= tables.hi_out[pos].swizzle_dyn(idx[pos]);
+            }
+
+            let result_lo = result_lo.to_array();
+            let result_hi = result_hi.to_array();
+            for i in 0..LANES {
+                chunk[2 * i] = result_lo[i];
+                chunk[2 * i + 1] = result_hi[i];
+            }
+        }
+
+        let remainder = chunks.into_remainder();
+        let log_m = usize::from(log_m);
+        for pair in remainder.chunks_exact_mut(2) {
+            let value = u16::from_le_bytes([pair[0], pair[1]]);
+            let product = if value == 0 {
+                0
+            } else {
+                let log_v = usize::from(self.log[usize::from(value)]);
+                self.exp[(log_v + log_m) % usize::from(GF_MODULUS)]
+            };
+            pair.copy_from_slice(&product.to_le_bytes());
+        }
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        let mut chunks = x.chunks_exact_mut(LANES).zip(y.chunks_exact(LANES));
+        for (x_chunk, y_chunk) in &mut chunks {
+            let xv = Simd::<u8, LANES>::from_slice(x_chunk);
+            let yv = Simd::<u8, LANES>::from_slice(y_chunk);
+            (xv ^ yv).copy_to_slice(x_chunk);
+        }
+
+        let done = x.len() - x.len() % LANES;
+        for i in done..x.len() {
+            x[i] ^= y[i];
+        }
+    }
+}
+
+impl Portable {
+    // `skew` is a field element (see `initialize_skew`), not a log; `skew == 0`
+    // means "no twiddle", so the multiply is skipped rather than zeroing
+    // `x`. `mul` wants a log, so convert using our own log table.
+    fn fft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement) {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        if skew != 0 {
+            self.mul(x, self.log[usize::from(skew)]);
+            Self::xor(y, x);
+        }
+        Self::xor(x, y);
+    }
+
+    fn ifft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement) {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        Self::xor(x, y);
+        if skew != 0 {
+            self.mul(x, self.log[usize::from(skew)]);
+            Self::xor(y, x);
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//! Low-level building blocks for Reed-Solomon encoding/decoding over GF(2^8).
+//!
+//! This mirrors [`super`] (the GF(2^16) engines) but uses an 8-bit field,
+//! which is sufficient whenever `original + recovery <= 256`. Elements are
+//! one byte instead of two, and the precomputed tables shrink from
+//! thousands of entries to [`GF8_ORDER`], which fits comfortably in L1.
+//!
+//! # Engines
+//!
+//! - [`NaiveGf8`]
+//!     - Simple reference implementation.
+//! - [`NoSimdGf8`]
+//!     - Basic optimized engine without SIMD so that it works on all CPUs.
+//! - [`Avx2Gf8`]
+//!     - Optimized engine that takes advantage of the x86(-64) AVX2 SIMD instructions.
+//! - [`Ssse3Gf8`]
+//!     - Optimized engine that takes advantage of the x86(-64) SSSE3 SIMD instructions.
+//! - [`NeonGf8`]
+//!     - Optimized engine that takes advantage of the AArch64 Neon SIMD instructions.
+//! - [`DefaultEngineGf8`]
+//!     - Default GF(2^8) engine which is used when no specific engine is given.
+//!     - Automatically selects best engine at runtime.
+
+pub use self::{
+    engine_default_gf8::DefaultEngineGf8, engine_naive_gf8::NaiveGf8, engine_nosimd_gf8::NoSimdGf8,
+};
+
+#[cfg(feature = "simd")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::{engine_avx2_gf8::Avx2Gf8, engine_ssse3_gf8::Ssse3Gf8};
+
+#[cfg(feature = "simd")]
+#[cfg(target_arch = "aarch64")]
+pub use self::engine_neon_gf8::NeonGf8;
+
+mod engine_default_gf8;
+mod engine_naive_gf8;
+mod engine_nosimd_gf8;
+
+#[cfg(feature = "simd")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod engine_avx2_gf8;
+#[cfg(feature = "simd")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod engine_ssse3_gf8;
+
+#[cfg(feature = "simd")]
+#[cfg(target_arch = "aarch64")]
+mod engine_neon_gf8;
+
+mod fwht8;
+
+use super::ShardsRefMut;
+
+// ======================================================================
+// CONST - PUBLIC
+
+/// Size of Galois field element [`GfElement8`] in bits.
+pub const GF8_BITS: usize = 8;
+
+/// Galois field order, i.e. number of elements.
+pub const GF8_ORDER: usize = 256;
+
+/// `GF8_ORDER - 1`
+pub const GF8_MODULUS: GfElement8 = 255;
+
+/// Galois field polynomial.
+pub const GF8_POLYNOMIAL: usize = 0x11D;
+
+/// TODO
+pub const CANTOR_BASIS_GF8: [GfElement8; GF8_BITS] =
+    [0x01, 0xD6, 0x98, 0x92, 0x56, 0xC8, 0x58, 0xE6];
+
+// ======================================================================
+// TYPE ALIASES - PUBLIC
+
+/// Galois field element.
+pub type GfElement8 = u8;
+
+// ======================================================================
+// FUNCTIONS - PUBLIC - Galois field operations
+
+/// Some kind of addition.
+#[inline(always)]
+pub fn add_mod8(x: GfElement8, y: GfElement8) -> GfElement8 {
+    let sum = u32::from(x) + u32::from(y);
+    (sum + (sum >> GF8_BITS)) as GfElement8
+}
+
+/// Some kind of subtraction.
+#[inline(always)]
+pub fn sub_mod8(x: GfElement8, y: GfElement8) -> GfElement8 {
+    let dif = u32::from(x).wrapping_sub(u32::from(y));
+    dif.wrapping_add(dif >> GF8_BITS) as GfElement8
+}
+
+// ======================================================================
+// FUNCTIONS - PUBLIC - misc
+
+/// Returns `true` if `original_count + recovery_count` fits the GF(2^8)
+/// engines in this module, i.e. [`rate`] can pick a [`gf8::Engine8`] engine
+/// instead of a GF(2^16) [`super::Engine`] engine.
+///
+/// [`rate`]: crate::rate
+/// [`gf8::Engine8`]: crate::engine::gf8::Engine8
+pub fn is_applicable(original_count: usize, recovery_count: usize) -> bool {
+    original_count.saturating_add(recovery_count) <= GF8_ORDER
+}
+
+// ======================================================================
+// FUNCTIONS - CRATE - Evaluate polynomial
+
+// Mirrors `super::eval_poly`, sized for GF(2^8).
+#[inline(always)]
+pub(crate) fn eval_poly(erasures: &mut [GfElement8; GF8_ORDER], truncated_size: usize) {
+    let log_walsh = tables_gf8::initialize_log_walsh8();
+
+    fwht8::fwht(erasures, truncated_size);
+
+    for (e, factor) in std::iter::zip(erasures.iter_mut(), log_walsh.iter()) {
+        let product = u32::from(*e) * u32::from(*factor);
+        *e = add_mod8(product as GfElement8, (product >> GF8_BITS) as GfElement8);
+    }
+
+    fwht8::fwht(erasures, GF8_ORDER);
+}
+
+// ======================================================================
+// FUNCTIONS - CRATE - shared FFT/IFFT, generic over any `Engine8`
+
+// Shared so that `NaiveGf8`/`NoSimdGf8`/SIMD engines don't each reimplement
+// the butterfly recursion; only `mul`/`xor` differ between them.
+pub(crate) fn fft<E: Engine8>(
+    engine: &E,
+    data: &mut ShardsRefMut,
+    pos: usize,
+    size: usize,
+    truncated_size: usize,
+    skew_delta: usize,
+) {
+    let skew_table = initialize_skew8();
+    let mut dist = size / 2;
+    let mut dist4 = size;
+    while dist != 0 {
+        let mut r = 0;
+        while r < truncated_size {
+            let skew = skew_table[skew_delta + r + dist - 1];
+            for i in r..r + dist {
+                engine.fft_butterfly(data, pos + i, pos + i + dist, skew);
+            }
+            r += dist4;
+        }
+        dist4 = dist;
+        dist /= 2;
+    }
+}
+
+pub(crate) fn ifft<E: Engine8>(
+    engine: &E,
+    data: &mut ShardsRefMut,
+    pos: usize,
+    size: usize,
+    truncated_size: usize,
+    skew_delta: usize,
+) {
+    let skew_table = initialize_skew8();
+    let mut dist = 1;
+    let mut dist4 = 4;
+    while dist4 <= size {
+        let mut r = 0;
+        while r < truncated_size {
+            let skew = skew_table[skew_delta + r + dist - 1];
+            for i in r..r + dist {
+                engine.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+            }
+            r += dist4;
+        }
+        dist = dist4;
+        dist4 *= 4;
+    }
+
+    if dist < size {
+        let skew = skew_table[skew_delta + dist - 1];
+        for i in 0..dist {
+            engine.ifft_butterfly(data, pos + i, pos + i + dist, skew);
+        }
+    }
+}
+
+// Skew factor derived from `CANTOR_BASIS_GF8`, used only by
+// `tables_gf8::initialize_log_walsh8` below. Cantor basis elements are
+// field elements, so combining them is GF(2^8) addition, i.e. XOR, not
+// `add_mod8` (which is mod-255 "log domain" addition and would give the
+// wrong evaluation point).
+//
+// This is *not* used for `fft`/`ifft` skew factors anymore - see
+// `initialize_skew8` for why a flat XOR-combination doesn't produce a
+// valid skew table for the butterfly recursion.
+fn cantor_skew(index: usize) -> GfElement8 {
+    let mut value: GfElement8 = 0;
+    for (bit, basis) in CANTOR_BASIS_GF8.iter().enumerate() {
+        if index & (1 << bit) != 0 {
+            value ^= *basis;
+        }
+    }
+    value
+}
+
+// Builds the log/exp tables `initialize_skew8` needs, same construction
+// every GF(2^8) engine uses (generator `2`, reduce by `GF8_POLYNOMIAL`).
+fn exp_log_tables8() -> ([GfElement8; GF8_ORDER], [GfElement8; GF8_ORDER]) {
+    let mut exp = [0; GF8_ORDER];
+    let mut log = [0; GF8_ORDER];
+    let mut x: u32 = 1;
+    for i in 0..usize::from(GF8_MODULUS) {
+        exp[i] = x as GfElement8;
+        log[x as usize] = i as GfElement8;
+        x <<= 1;
+        if x & GF8_ORDER as u32 != 0 {
+            x ^= GF8_POLYNOMIAL as u32;
+        }
+    }
+    (exp, log)
+}
+
+#[inline(always)]
+fn mul_log8(
+    exp: &[GfElement8; GF8_ORDER],
+    log: &[GfElement8; GF8_ORDER],
+    a: GfElement8,
+    log_b: GfElement8,
+) -> GfElement8 {
+    if a == 0 {
+        0
+    } else {
+        exp[usize::from(add_mod8(log[usize::from(a)], log_b))]
+    }
+}
+
+/// GF(2^8) analogue of [`super::initialize_skew`] - see its doc comment for
+/// the construction. `fft`/`ifft` above build this table once per call
+/// rather than consulting a fixed basis per index.
+fn initialize_skew8() -> [GfElement8; GF8_MODULUS as usize] {
+    let (exp, log) = exp_log_tables8();
+
+    let mut temp: [GfElement8; GF8_BITS - 1] = [0; GF8_BITS - 1];
+    for (i, t) in temp.iter_mut().enumerate() {
+        *t = (1u32 << (i + 1)) as GfElement8;
+    }
+
+    let mut skew = [0 as GfElement8; GF8_MODULUS as usize];
+
+    for m in 0..GF8_BITS - 1 {
+        let step = 1usize << (m + 1);
+        skew[(1 << m) - 1] = 0;
+
+        for i in m..GF8_BITS - 1 {
+            let s = 1usize << (i + 1);
+            let mut j = (1usize << m) - 1;
+            while j < s {
+                skew[j + s] = skew[j] ^ temp[i];
+                j += step;
+            }
+        }
+
+        // `temp[m]` becomes `log(1 / (temp[m] * (temp[m] ^ 1)))`, the factor
+        // that normalizes the remaining basis vectors below.
+        let product = mul_log8(&exp, &log, temp[m], log[usize::from(temp[m] ^ 1)]);
+        temp[m] = sub_mod8(0, log[usize::from(product)]);
+
+        for i in (m + 1)..GF8_BITS - 1 {
+            let sum = add_mod8(log[usize::from(temp[i] ^ 1)], temp[m]);
+            temp[i] = mul_log8(&exp, &log, temp[i], sum);
+        }
+    }
+
+    skew
+}
+
+mod tables_gf8 {
+    //! Runtime-computed GF(2^8) log-Walsh table (256 entries).
+
+    use super::{cantor_skew, GfElement8, GF8_ORDER, GF8_POLYNOMIAL};
+
+    pub(super) fn initialize_log_walsh8() -> [GfElement8; GF8_ORDER] {
+        let mut log = [0; GF8_ORDER];
+        let mut x: u32 = 1;
+        for i in 0..usize::from(super::GF8_MODULUS) {
+            log[x as usize] = i as GfElement8;
+            x <<= 1;
+            if x & GF8_ORDER as u32 != 0 {
+                x ^= GF8_POLYNOMIAL as u32;
+            }
+        }
+
+        let mut log_walsh = [0; GF8_ORDER];
+        for (i, w) in log_walsh.iter_mut().enumerate() {
+            *w = log[usize::from(cantor_skew(i))];
+        }
+        super::fwht8::fwht(&mut log_walsh, GF8_ORDER);
+        log_walsh
+    }
+}
+
+// ======================================================================
+// Engine8 - PUBLIC
+
+/// Implementation of basic low-level algorithms needed
+/// for Reed-Solomon encoding/decoding over GF(2^8).
+///
+/// These algorithms are not properly documented.
+///
+/// [`NaiveGf8`] engine is provided for those who want to
+/// study the source code to understand [`Engine8`].
+pub trait Engine8 {
+    // ============================================================
+    // REQUIRED
+
+    /// In-place decimation-in-time FFT (fast Fourier transform).
+    ///
+    /// Same contract as [`super::Engine::fft`], sized for GF(2^8).
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    );
+
+    /// In-place decimation-in-time IFFT (inverse fast Fourier transform).
+    ///
+    /// Same contract as [`super::Engine::ifft`], sized for GF(2^8).
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    );
+
+    /// `x[] *= log_m`
+    fn mul(&self, x: &mut [u8], log_m: GfElement8);
+
+    /// Discrete log of `value`, i.e. the `log_m` that makes `mul` multiply
+    /// by `value`. `value` must be nonzero.
+    ///
+    /// Used to convert the field-element skew factors from [`fft`]/[`ifft`]
+    /// (see [`initialize_skew8`]) into the log domain [`Self::mul`] expects.
+    fn discrete_log(&self, value: GfElement8) -> GfElement8;
+
+    /// `x[] ^= y[]`
+    fn xor(x: &mut [u8], y: &[u8])
+    where
+        Self: Sized;
+
+    // ============================================================
+    // PROVIDED
+
+    /// Evaluate polynomial.
+    fn eval_poly(erasures: &mut [GfElement8; GF8_ORDER], truncated_size: usize)
+    where
+        Self: Sized,
+    {
+        eval_poly(erasures, truncated_size)
+    }
+
+    /// FFT with `skew_delta = pos + size`.
+    #[inline(always)]
+    fn fft_skew_end(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+    ) {
+        self.fft(data, pos, size, truncated_size, pos + size)
+    }
+
+    /// Formal derivative.
+    fn formal_derivative(data: &mut ShardsRefMut)
+    where
+        Self: Sized,
+    {
+        for i in 1..data.len() {
+            let width: usize = ((i ^ (i - 1)) + 1) >> 1;
+            Self::xor_within(data, i - width, i, width);
+        }
+    }
+
+    /// IFFT with `skew_delta = pos + size`.
+    #[inline(always)]
+    fn ifft_skew_end(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+    ) {
+        self.ifft(data, pos, size, truncated_size, pos + size)
+    }
+
+    /// `data[x .. x + count] ^= data[y .. y + count]`
+    ///
+    /// Ranges must not overlap.
+    #[inline(always)]
+    fn xor_within(data: &mut ShardsRefMut, x: usize, y: usize, count: usize)
+    where
+        Self: Sized,
+    {
+        let (xs, ys) = data.flat2_mut(x, y, count);
+        Self::xor(xs, ys);
+    }
+
+    /// Single FFT butterfly on shards `i` and `j`, used by the shared
+    /// [`fft`] recursion. `skew` is a field element (see [`initialize_skew8`]),
+    /// not a log; a `skew` of `0` means "no twiddle", so the multiply is
+    /// skipped rather than zeroing `x`.
+    #[inline(always)]
+    fn fft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement8)
+    where
+        Self: Sized,
+    {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        if skew != 0 {
+            self.mul(x, self.discrete_log(skew));
+            Self::xor(y, x);
+        }
+        Self::xor(x, y);
+    }
+
+    /// Single IFFT butterfly on shards `i` and `j`, used by the shared
+    /// [`ifft`] recursion. See [`Self::fft_butterfly`] for `skew`'s domain.
+    #[inline(always)]
+    fn ifft_butterfly(&self, data: &mut ShardsRefMut, i: usize, j: usize, skew: GfElement8)
+    where
+        Self: Sized,
+    {
+        let (x, y) = data.flat2_mut(i, j, 1);
+        Self::xor(x, y);
+        if skew != 0 {
+            self.mul(x, self.discrete_log(skew));
+            Self::xor(y, x);
+        }
+    }
+}
+
+// ======================================================================
+// TESTS
+
+// Unlike the GF(2^16) engines, these GF(2^8) engines aren't wired into
+// `rate` yet (see the `engine` module docs), so they get direct
+// engine-level coverage here instead of an indirect HighRate/LowRate
+// roundtrip test. An actual `fft`/`ifft` roundtrip test (the test that
+// would catch a broken skew table most directly) isn't possible here
+// either: it needs a real `ShardsRefMut`/`Shards`, and `shards.rs` isn't
+// part of this checkout (only referenced by `mod shards;` in
+// `super`). `test_initialize_skew8_zero_at_block_starts` below is
+// the next best thing - a concrete, checkable property the skew table
+// must have for the butterfly recursion to be correct, which the old
+// `cantor_skew`-based table provably did not.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub_mod8_roundtrip() {
+        for x in 0..=GF8_MODULUS {
+            for y in [0, 1, 2, GF8_MODULUS] {
+                assert_eq!(sub_mod8(add_mod8(x, y), y), x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cantor_skew_is_involution_free_xor() {
+        // `cantor_skew` XORs a subset of `CANTOR_BASIS_GF8`, so combining
+        // two index sets must combine their values the same way a GF(2^8)
+        // addition (XOR) would. Only `initialize_log_walsh8` still uses
+        // `cantor_skew`; `fft`/`ifft` use `initialize_skew8` instead (see
+        // `test_initialize_skew8_zero_at_block_starts` for why).
+        for a in 0..GF8_ORDER {
+            for b in [0x01usize, 0x10, 0x55, 0xAA] {
+                assert_eq!(
+                    cantor_skew(a) ^ cantor_skew(b),
+                    cantor_skew(a ^ b),
+                    "cantor_skew({a}) ^ cantor_skew({b}) != cantor_skew({a} ^ {b})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_initialize_skew8_zero_at_block_starts() {
+        // Every block the FFT/IFFT recursion visits starts at `dist - 1`
+        // for some power-of-two `dist` (with `skew_delta == 0`), and that
+        // first entry must carry no twiddle at all - `cantor_skew` fails
+        // this (e.g. `cantor_skew(1) == CANTOR_BASIS_GF8[0] != 0`), which is
+        // why `fft`/`ifft` no longer use it.
+        let skew = initialize_skew8();
+        for m in 0..GF8_BITS - 1 {
+            assert_eq!(skew[(1 << m) - 1], 0, "skew[2^{m} - 1] should be 0");
+        }
+    }
+
+    #[test]
+    fn test_initialize_skew8_nonzero_entries_distinct_per_level() {
+        // See `test_initialize_skew_nonzero_entries_distinct_per_level` in
+        // `engine.rs`: distinct within each level, zero only at the
+        // `GF8_BITS` entries `2^m - 1`.
+        let skew = initialize_skew8();
+        let mut seen = [[false; GF8_ORDER]; GF8_BITS];
+        let mut nonzero_count = 0;
+        for (index, &value) in skew.iter().enumerate() {
+            if value != 0 {
+                let level = index.trailing_ones() as usize;
+                assert!(
+                    !seen[level][usize::from(value)],
+                    "duplicate skew value {value} at level {level}"
+                );
+                seen[level][usize::from(value)] = true;
+                nonzero_count += 1;
+            }
+        }
+        assert_eq!(nonzero_count, usize::from(GF8_MODULUS) - GF8_BITS);
+    }
+
+    #[test]
+    fn test_naive_nosimd_mul_agree() {
+        // `NaiveGf8` is the test oracle; every other engine's `mul` must
+        // match it for every `(log_m, byte)` pair.
+        let naive = NaiveGf8::new();
+        let nosimd = NoSimdGf8::new();
+        for log_m in 0..=GF8_MODULUS {
+            for byte in 0..=GF8_MODULUS {
+                let mut naive_byte = [byte];
+                let mut nosimd_byte = [byte];
+                naive.mul(&mut naive_byte, log_m);
+                nosimd.mul(&mut nosimd_byte, log_m);
+                assert_eq!(
+                    naive_byte, nosimd_byte,
+                    "mismatch at log_m={log_m}, byte={byte}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_discrete_log_inverts_mul_by_generator() {
+        // `discrete_log(value)` must be the `log_m` that `mul` uses to
+        // reach `value` starting from `1`.
+        let engine = NoSimdGf8::new();
+        for value in 1..=GF8_MODULUS {
+            let log_value = engine.discrete_log(value);
+            let mut one = [1u8];
+            engine.mul(&mut one, log_value);
+            assert_eq!(one[0], value);
+        }
+    }
+}
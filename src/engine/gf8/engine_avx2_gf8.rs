@@ -0,0 +1,144 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use super::{fft, ifft, Engine8, GfElement8, GF8_MODULUS, GF8_ORDER, GF8_POLYNOMIAL};
+use crate::engine::ShardsRefMut;
+
+/// Optimized [`Engine8`] that takes advantage of the x86(-64) AVX2 SIMD
+/// instructions.
+///
+/// Same nibble-split `pshufb` trick as [`super::Ssse3Gf8`], but processing
+/// 32 bytes per `vpshufb` instead of 16.
+#[derive(Clone)]
+pub struct Avx2Gf8 {
+    exp: [GfElement8; GF8_ORDER],
+    log: [GfElement8; GF8_ORDER],
+}
+
+impl Avx2Gf8 {
+    /// Creates new [`Avx2Gf8`], initializing log/exp tables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the AVX2 instruction set is not supported.
+    pub fn new() -> Self {
+        assert!(is_x86_feature_detected!("avx2"));
+
+        let mut exp = [0; GF8_ORDER];
+        let mut log = [0; GF8_ORDER];
+        let mut x: u32 = 1;
+        for i in 0..usize::from(GF8_MODULUS) {
+            exp[i] = x as GfElement8;
+            log[x as usize] = i as GfElement8;
+            x <<= 1;
+            if x & GF8_ORDER as u32 != 0 {
+                x ^= GF8_POLYNOMIAL as u32;
+            }
+        }
+
+        Self { exp, log }
+    }
+
+    // Builds the low/high nibble tables for one multiplier given in log form,
+    // duplicated across both 128-bit lanes for `vpshufb`.
+    fn nibble_tables(&self, log_m: GfElement8) -> ([u8; 32], [u8; 32]) {
+        let log_m = usize::from(log_m);
+        let mul = |v: usize| -> u8 {
+            if v == 0 {
+                0
+            } else {
+                let log_v = usize::from(self.log[v]);
+                self.exp[(log_v + log_m) % usize::from(GF8_MODULUS)]
+            }
+        };
+
+        let mut lo = [0u8; 32];
+        let mut hi = [0u8; 32];
+        for i in 0..16 {
+            let lo_v = mul(i);
+            let hi_v = mul(i << 4);
+            lo[i] = lo_v;
+            lo[i + 16] = lo_v;
+            hi[i] = hi_v;
+            hi[i + 16] = hi_v;
+        }
+        (lo, hi)
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_avx2(&self, x: &mut [u8], log_m: GfElement8) {
+        let (lo_table, hi_table) = self.nibble_tables(log_m);
+        let lo_shuf = _mm256_loadu_si256(lo_table.as_ptr().cast());
+        let hi_shuf = _mm256_loadu_si256(hi_table.as_ptr().cast());
+        let low_mask = _mm256_set1_epi8(0x0f);
+
+        let mut chunks = x.chunks_exact_mut(32);
+        for chunk in &mut chunks {
+            let v = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let lo_idx = _mm256_and_si256(v, low_mask);
+            let hi_idx = _mm256_and_si256(_mm256_srli_epi16(v, 4), low_mask);
+            let lo_prod = _mm256_shuffle_epi8(lo_shuf, lo_idx);
+            let hi_prod = _mm256_shuffle_epi8(hi_shuf, hi_idx);
+            let prod = _mm256_xor_si256(lo_prod, hi_prod);
+            _mm256_storeu_si256(chunk.as_mut_ptr().cast(), prod);
+        }
+
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            for byte in remainder.iter_mut() {
+                let lo = lo_table[usize::from(*byte & 0x0f)];
+                let hi = hi_table[usize::from((*byte >> 4) & 0x0f)];
+                *byte = lo ^ hi;
+            }
+        }
+    }
+}
+
+impl Default for Avx2Gf8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine8 for Avx2Gf8 {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        fft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        ifft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement8) {
+        // SAFETY: `Avx2Gf8::new` checks that AVX2 is supported.
+        unsafe {
+            self.mul_avx2(x, log_m);
+        }
+    }
+
+    fn discrete_log(&self, value: GfElement8) -> GfElement8 {
+        self.log[usize::from(value)]
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        for (x, y) in std::iter::zip(x.iter_mut(), y.iter()) {
+            *x ^= *y;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use super::{Engine8, GfElement8, NoSimdGf8};
+use crate::engine::ShardsRefMut;
+
+#[cfg(feature = "simd")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use super::{Avx2Gf8, Ssse3Gf8};
+
+#[cfg(feature = "simd")]
+#[cfg(target_arch = "aarch64")]
+use super::NeonGf8;
+
+/// Default GF(2^8) engine which is used when no specific engine is given.
+///
+/// Automatically selects the best available GF(2^8) engine at runtime,
+/// same as [`super::super::DefaultEngine`] does for GF(2^16).
+#[derive(Clone)]
+pub struct DefaultEngineGf8(DefaultEngineGf8Inner);
+
+#[derive(Clone)]
+enum DefaultEngineGf8Inner {
+    #[cfg(feature = "simd")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2(Avx2Gf8),
+    #[cfg(feature = "simd")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Ssse3(Ssse3Gf8),
+    #[cfg(feature = "simd")]
+    #[cfg(target_arch = "aarch64")]
+    Neon(NeonGf8),
+    NoSimd(NoSimdGf8),
+}
+
+impl DefaultEngineGf8 {
+    /// Creates new [`DefaultEngineGf8`] by choosing and initializing the
+    /// best engine for the current CPU.
+    #[allow(clippy::let_and_return)]
+    pub fn new() -> Self {
+        #[cfg(feature = "simd")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Self(DefaultEngineGf8Inner::Avx2(Avx2Gf8::new()));
+            }
+            if is_x86_feature_detected!("ssse3") {
+                return Self(DefaultEngineGf8Inner::Ssse3(Ssse3Gf8::new()));
+            }
+        }
+
+        #[cfg(feature = "simd")]
+        #[cfg(target_arch = "aarch64")]
+        {
+            return Self(DefaultEngineGf8Inner::Neon(NeonGf8::new()));
+        }
+
+        #[allow(unreachable_code)]
+        Self(DefaultEngineGf8Inner::NoSimd(NoSimdGf8::new()))
+    }
+
+    /// Like [`Self::new`] but never selects a SIMD engine. Mostly useful
+    /// for testing.
+    pub fn force_no_simd() -> Self {
+        Self(DefaultEngineGf8Inner::NoSimd(NoSimdGf8::new()))
+    }
+}
+
+impl Default for DefaultEngineGf8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine8 for DefaultEngineGf8 {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        match &self.0 {
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Avx2(engine) => {
+                engine.fft(data, pos, size, truncated_size, skew_delta)
+            }
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Ssse3(engine) => {
+                engine.fft(data, pos, size, truncated_size, skew_delta)
+            }
+            #[cfg(feature = "simd")]
+            #[cfg(target_arch = "aarch64")]
+            DefaultEngineGf8Inner::Neon(engine) => {
+                engine.fft(data, pos, size, truncated_size, skew_delta)
+            }
+            DefaultEngineGf8Inner::NoSimd(engine) => {
+                engine.fft(data, pos, size, truncated_size, skew_delta)
+            }
+        }
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        match &self.0 {
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Avx2(engine) => {
+                engine.ifft(data, pos, size, truncated_size, skew_delta)
+            }
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Ssse3(engine) => {
+                engine.ifft(data, pos, size, truncated_size, skew_delta)
+            }
+            #[cfg(feature = "simd")]
+            #[cfg(target_arch = "aarch64")]
+            DefaultEngineGf8Inner::Neon(engine) => {
+                engine.ifft(data, pos, size, truncated_size, skew_delta)
+            }
+            DefaultEngineGf8Inner::NoSimd(engine) => {
+                engine.ifft(data, pos, size, truncated_size, skew_delta)
+            }
+        }
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement8) {
+        match &self.0 {
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Avx2(engine) => engine.mul(x, log_m),
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Ssse3(engine) => engine.mul(x, log_m),
+            #[cfg(feature = "simd")]
+            #[cfg(target_arch = "aarch64")]
+            DefaultEngineGf8Inner::Neon(engine) => engine.mul(x, log_m),
+            DefaultEngineGf8Inner::NoSimd(engine) => engine.mul(x, log_m),
+        }
+    }
+
+    fn discrete_log(&self, value: GfElement8) -> GfElement8 {
+        match &self.0 {
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Avx2(engine) => engine.discrete_log(value),
+            #[cfg(feature = "simd")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            DefaultEngineGf8Inner::Ssse3(engine) => engine.discrete_log(value),
+            #[cfg(feature = "simd")]
+            #[cfg(target_arch = "aarch64")]
+            DefaultEngineGf8Inner::Neon(engine) => engine.discrete_log(value),
+            DefaultEngineGf8Inner::NoSimd(engine) => engine.discrete_log(value),
+        }
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        NoSimdGf8::xor(x, y)
+    }
+}
@@ -0,0 +1,102 @@
+use super::{fft, ifft, Engine8, GfElement8, GF8_MODULUS, GF8_ORDER, GF8_POLYNOMIAL};
+use crate::engine::ShardsRefMut;
+
+/// Simple reference implementation of [`Engine8`] over GF(2^8).
+///
+/// [`NaiveGf8`] is meant for those who want to understand [`Engine8`]
+/// by reading the source code, and as a test oracle for the optimized
+/// GF(2^8) engines. It's not meant to be used in production.
+#[derive(Clone)]
+pub struct NaiveGf8 {
+    // `mul_table[log_m][byte] == byte * exp[log_m]`
+    mul_table: [[GfElement8; GF8_ORDER]; GF8_ORDER],
+    log: [GfElement8; GF8_ORDER],
+}
+
+impl NaiveGf8 {
+    /// Creates new [`NaiveGf8`], initializing 64 KiB of multiplication tables.
+    pub fn new() -> Self {
+        let exp = exp_table();
+
+        let mut log = [0; GF8_ORDER];
+        for (i, e) in exp.iter().enumerate().take(usize::from(GF8_MODULUS)) {
+            log[usize::from(*e)] = i as GfElement8;
+        }
+
+        let mut mul_table = [[0; GF8_ORDER]; GF8_ORDER];
+        for (log_m, row) in mul_table.iter_mut().enumerate() {
+            for (byte, product) in row.iter_mut().enumerate() {
+                *product = if byte == 0 {
+                    0
+                } else {
+                    let sum = usize::from(log[byte]) + log_m;
+                    exp[sum % usize::from(GF8_MODULUS)]
+                };
+            }
+        }
+
+        Self { mul_table, log }
+    }
+}
+
+impl Default for NaiveGf8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine8 for NaiveGf8 {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        fft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        ifft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement8) {
+        let table = &self.mul_table[usize::from(log_m)];
+        for byte in x.iter_mut() {
+            *byte = table[usize::from(*byte)];
+        }
+    }
+
+    fn discrete_log(&self, value: GfElement8) -> GfElement8 {
+        self.log[usize::from(value)]
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        for (x, y) in std::iter::zip(x.iter_mut(), y.iter()) {
+            *x ^= *y;
+        }
+    }
+}
+
+// Generator powers for `GF8_POLYNOMIAL`, used to bootstrap `NaiveGf8`'s
+// plain (non-log-indexed) multiplication table.
+fn exp_table() -> [GfElement8; GF8_ORDER] {
+    let mut exp = [0; GF8_ORDER];
+    let mut x: u32 = 1;
+    for e in exp.iter_mut().take(usize::from(GF8_MODULUS)) {
+        *e = x as GfElement8;
+        x <<= 1;
+        if x & GF8_ORDER as u32 != 0 {
+            x ^= GF8_POLYNOMIAL as u32;
+        }
+    }
+    exp
+}
@@ -0,0 +1,129 @@
+use std::arch::aarch64::*;
+
+use super::{fft, ifft, Engine8, GfElement8, GF8_MODULUS, GF8_ORDER, GF8_POLYNOMIAL};
+use crate::engine::ShardsRefMut;
+
+/// Optimized [`Engine8`] that takes advantage of the AArch64 Neon SIMD
+/// instructions.
+///
+/// Same nibble-split trick as [`super::Ssse3Gf8`], using `vqtbl1q_u8`
+/// instead of `pshufb`.
+#[derive(Clone)]
+pub struct NeonGf8 {
+    exp: [GfElement8; GF8_ORDER],
+    log: [GfElement8; GF8_ORDER],
+}
+
+impl NeonGf8 {
+    /// Creates new [`NeonGf8`], initializing log/exp tables.
+    pub fn new() -> Self {
+        let mut exp = [0; GF8_ORDER];
+        let mut log = [0; GF8_ORDER];
+        let mut x: u32 = 1;
+        for i in 0..usize::from(GF8_MODULUS) {
+            exp[i] = x as GfElement8;
+            log[x as usize] = i as GfElement8;
+            x <<= 1;
+            if x & GF8_ORDER as u32 != 0 {
+                x ^= GF8_POLYNOMIAL as u32;
+            }
+        }
+
+        Self { exp, log }
+    }
+
+    fn nibble_tables(&self, log_m: GfElement8) -> ([u8; 16], [u8; 16]) {
+        let log_m = usize::from(log_m);
+        let mul = |v: usize| -> u8 {
+            if v == 0 {
+                0
+            } else {
+                let log_v = usize::from(self.log[v]);
+                self.exp[(log_v + log_m) % usize::from(GF8_MODULUS)]
+            }
+        };
+
+        let mut lo = [0u8; 16];
+        let mut hi = [0u8; 16];
+        for i in 0..16 {
+            lo[i] = mul(i);
+            hi[i] = mul(i << 4);
+        }
+        (lo, hi)
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn mul_neon(&self, x: &mut [u8], log_m: GfElement8) {
+        let (lo_table, hi_table) = self.nibble_tables(log_m);
+        let lo_shuf = vld1q_u8(lo_table.as_ptr());
+        let hi_shuf = vld1q_u8(hi_table.as_ptr());
+        let low_mask = vdupq_n_u8(0x0f);
+
+        let mut chunks = x.chunks_exact_mut(16);
+        for chunk in &mut chunks {
+            let v = vld1q_u8(chunk.as_ptr());
+            let lo_idx = vandq_u8(v, low_mask);
+            let hi_idx = vandq_u8(vshrq_n_u8(v, 4), low_mask);
+            let lo_prod = vqtbl1q_u8(lo_shuf, lo_idx);
+            let hi_prod = vqtbl1q_u8(hi_shuf, hi_idx);
+            let prod = veorq_u8(lo_prod, hi_prod);
+            vst1q_u8(chunk.as_mut_ptr(), prod);
+        }
+
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            for byte in remainder.iter_mut() {
+                let lo = lo_table[usize::from(*byte & 0x0f)];
+                let hi = hi_table[usize::from((*byte >> 4) & 0x0f)];
+                *byte = lo ^ hi;
+            }
+        }
+    }
+}
+
+impl Default for NeonGf8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine8 for NeonGf8 {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        fft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        ifft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement8) {
+        // SAFETY: Neon is always available on AArch64.
+        unsafe {
+            self.mul_neon(x, log_m);
+        }
+    }
+
+    fn discrete_log(&self, value: GfElement8) -> GfElement8 {
+        self.log[usize::from(value)]
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        for (x, y) in std::iter::zip(x.iter_mut(), y.iter()) {
+            *x ^= *y;
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::{fft, ifft, Engine8, GfElement8, GF8_MODULUS, GF8_ORDER, GF8_POLYNOMIAL};
+use crate::engine::ShardsRefMut;
+
+/// Basic optimized [`Engine8`] without SIMD so that it works on all CPUs.
+///
+/// Unlike [`super::NaiveGf8`], [`NoSimdGf8`] only keeps 256-entry log/exp
+/// tables (half a kilobyte total) instead of a full 64 KiB multiplication
+/// table, which is the whole point of the GF(2^8) family.
+#[derive(Clone)]
+pub struct NoSimdGf8 {
+    log: [GfElement8; GF8_ORDER],
+    exp: [GfElement8; GF8_ORDER],
+}
+
+impl NoSimdGf8 {
+    /// Creates new [`NoSimdGf8`], initializing log/exp tables.
+    pub fn new() -> Self {
+        let mut exp = [0; GF8_ORDER];
+        let mut log = [0; GF8_ORDER];
+
+        let mut x: u32 = 1;
+        for i in 0..usize::from(GF8_MODULUS) {
+            exp[i] = x as GfElement8;
+            log[x as usize] = i as GfElement8;
+            x <<= 1;
+            if x & GF8_ORDER as u32 != 0 {
+                x ^= GF8_POLYNOMIAL as u32;
+            }
+        }
+
+        Self { log, exp }
+    }
+}
+
+impl Default for NoSimdGf8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine8 for NoSimdGf8 {
+    fn fft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        fft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn ifft(
+        &self,
+        data: &mut ShardsRefMut,
+        pos: usize,
+        size: usize,
+        truncated_size: usize,
+        skew_delta: usize,
+    ) {
+        ifft(self, data, pos, size, truncated_size, skew_delta)
+    }
+
+    fn mul(&self, x: &mut [u8], log_m: GfElement8) {
+        let log_m = usize::from(log_m);
+        for byte in x.iter_mut() {
+            if *byte != 0 {
+                let log_byte = usize::from(self.log[usize::from(*byte)]);
+                *byte = self.exp[(log_byte + log_m) % usize::from(GF8_MODULUS)];
+            }
+        }
+    }
+
+    fn discrete_log(&self, value: GfElement8) -> GfElement8 {
+        self.log[usize::from(value)]
+    }
+
+    fn xor(x: &mut [u8], y: &[u8]) {
+        for (x, y) in std::iter::zip(x.iter_mut(), y.iter()) {
+            *x ^= *y;
+        }
+    }
+}
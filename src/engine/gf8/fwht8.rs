@@ -0,0 +1,21 @@
+//! Fast Walsh-Hadamard transform over GF(2^8), used by [`super::eval_poly`].
+//!
+//! Mirrors `engine::fwht` (see that module) but sized for [`super::GF8_ORDER`].
+
+use super::{add_mod8, sub_mod8, GfElement8, GF8_ORDER};
+
+pub(super) fn fwht(data: &mut [GfElement8; GF8_ORDER], truncated_size: usize) {
+    let mut dist = 1;
+    while dist < GF8_ORDER {
+        let mut r = 0;
+        while r < truncated_size {
+            for i in r..r + dist {
+                let (a, b) = (data[i], data[i + dist]);
+                data[i] = add_mod8(a, b);
+                data[i + dist] = sub_mod8(a, b);
+            }
+            r += dist * 2;
+        }
+        dist *= 2;
+    }
+}